@@ -0,0 +1,132 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under both the MIT license found in the
+ * LICENSE-MIT file in the root directory of this source tree and the Apache
+ * License, Version 2.0 found in the LICENSE-APACHE file in the root directory
+ * of this source tree.
+ */
+
+use futures::{
+    future::FutureExt,
+    stream::Stream,
+    task::{Context, Poll},
+};
+use pin_project::pin_project;
+use std::pin::Pin;
+use std::time::Duration;
+use tokio::time::Delay;
+
+/// A stream that batches items from an inner stream into `Vec<T>` chunks, flushing a batch once
+/// it reaches `max_size` items or once `duration` has elapsed since the first item of the current
+/// batch was buffered, whichever happens first. Any partial batch still buffered when the inner
+/// stream ends is flushed before this stream itself yields `None`.
+#[pin_project]
+pub struct ChunksTimeout<S: Stream> {
+    #[pin]
+    inner: S,
+    max_size: usize,
+    duration: Duration,
+    buffer: Vec<S::Item>,
+    deadline: Option<Delay>,
+}
+
+impl<S: Stream> ChunksTimeout<S> {
+    /// Create a new [ChunksTimeout].
+    pub fn new(inner: S, max_size: usize, duration: Duration) -> Self {
+        Self {
+            inner,
+            max_size,
+            duration,
+            buffer: Vec::new(),
+            deadline: None,
+        }
+    }
+}
+
+impl<S: Stream> Stream for ChunksTimeout<S> {
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            if let Some(deadline) = this.deadline.as_mut() {
+                if deadline.poll_unpin(cx).is_ready() {
+                    *this.deadline = None;
+                    return Poll::Ready(Some(std::mem::take(this.buffer)));
+                }
+            }
+
+            match this.inner.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    if this.buffer.is_empty() {
+                        *this.deadline = Some(tokio::time::delay_for(*this.duration));
+                    }
+                    this.buffer.push(item);
+
+                    if this.buffer.len() >= *this.max_size {
+                        *this.deadline = None;
+                        return Poll::Ready(Some(std::mem::take(this.buffer)));
+                    }
+                }
+                Poll::Ready(None) => {
+                    if this.buffer.is_empty() {
+                        return Poll::Ready(None);
+                    }
+                    *this.deadline = None;
+                    return Poll::Ready(Some(std::mem::take(this.buffer)));
+                }
+                Poll::Pending => {
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use anyhow::Error;
+    use futures::stream::StreamExt;
+
+    #[tokio::test]
+    async fn test_chunks_timeout_max_size() -> Result<(), Error> {
+        tokio::time::pause();
+
+        let s = async_stream::stream! {
+            yield 1;
+            yield 2;
+            yield 3;
+        };
+
+        let mut s = ChunksTimeout::new(s.boxed(), 2, Duration::from_secs(10));
+
+        assert_eq!(s.next().await, Some(vec![1, 2]));
+        assert_eq!(s.next().await, Some(vec![3]));
+        assert_eq!(s.next().await, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_chunks_timeout_elapses() -> Result<(), Error> {
+        tokio::time::pause();
+
+        let s = async_stream::stream! {
+            yield 1;
+            tokio::time::advance(Duration::from_secs(2)).await;
+            yield 2;
+        };
+
+        let mut s = ChunksTimeout::new(s.boxed(), 100, Duration::from_secs(1));
+
+        assert_eq!(s.next().await, Some(vec![1]));
+        assert_eq!(s.next().await, Some(vec![2]));
+        assert_eq!(s.next().await, None);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under both the MIT license found in the
+ * LICENSE-MIT file in the root directory of this source tree and the Apache
+ * License, Version 2.0 found in the LICENSE-APACHE file in the root directory
+ * of this source tree.
+ */
+
+use futures::stream::Stream;
+use std::time::Duration;
+
+use super::{ChunksTimeout, StreamWithItemTimeout, StreamWithTimeout, Throttle};
+
+/// An extension trait that adds the timing combinators in this module to all [Stream]s, mirroring
+/// the ergonomics of `tokio_stream::StreamExt`. This lets callers chain adapters fluently, e.g.
+/// `my_stream.with_item_timeout(d).chunks_timeout(100, d2)`, instead of naming and wrapping each
+/// constructor by hand.
+pub trait TimedStreamExt: Stream {
+    /// Enforce a single deadline across the whole stream. See [StreamWithTimeout].
+    fn with_total_timeout(self, duration: Duration) -> StreamWithTimeout<Self>
+    where
+        Self: Sized,
+    {
+        StreamWithTimeout::new(self, duration)
+    }
+
+    /// Enforce a per-item deadline that resets after every yielded item. See
+    /// [StreamWithItemTimeout].
+    fn with_item_timeout(self, duration: Duration) -> StreamWithItemTimeout<Self>
+    where
+        Self: Sized,
+    {
+        StreamWithItemTimeout::new(self, duration)
+    }
+
+    /// Batch items into `Vec`s, flushed by size or by a timeout since the first buffered item.
+    /// See [ChunksTimeout].
+    fn chunks_timeout(self, max_size: usize, duration: Duration) -> ChunksTimeout<Self>
+    where
+        Self: Sized,
+    {
+        ChunksTimeout::new(self, max_size, duration)
+    }
+
+    /// Rate-limit items to at most one per `duration`. See [Throttle].
+    fn throttle(self, duration: Duration) -> Throttle<Self>
+    where
+        Self: Sized,
+    {
+        Throttle::new(self, duration)
+    }
+}
+
+impl<S: Stream> TimedStreamExt for S {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use anyhow::Error;
+    use futures::stream::{StreamExt, TryStreamExt};
+
+    #[tokio::test]
+    async fn test_with_item_timeout_via_ext() -> Result<(), Error> {
+        tokio::time::pause();
+
+        let s = async_stream::stream! {
+            yield Result::<(), Error>::Ok(());
+        };
+
+        let mut s = s.boxed().with_item_timeout(Duration::from_secs(1));
+
+        assert!(s.try_next().await?.is_some());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_chunks_timeout_via_ext() -> Result<(), Error> {
+        tokio::time::pause();
+
+        let s = async_stream::stream! {
+            yield 1;
+            yield 2;
+        };
+
+        let mut s = s.boxed().chunks_timeout(2, Duration::from_secs(1));
+
+        assert_eq!(s.next().await, Some(vec![1, 2]));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,20 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under both the MIT license found in the
+ * LICENSE-MIT file in the root directory of this source tree and the Apache
+ * License, Version 2.0 found in the LICENSE-APACHE file in the root directory
+ * of this source tree.
+ */
+
+mod chunks_timeout;
+mod ext;
+mod stream_with_item_timeout;
+mod stream_with_timeout;
+mod throttle;
+
+pub use chunks_timeout::ChunksTimeout;
+pub use ext::TimedStreamExt;
+pub use stream_with_item_timeout::{StreamItemTimeoutError, StreamWithItemTimeout};
+pub use stream_with_timeout::{StreamTimeoutError, StreamWithTimeout};
+pub use throttle::Throttle;
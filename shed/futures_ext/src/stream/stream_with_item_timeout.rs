@@ -0,0 +1,128 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under both the MIT license found in the
+ * LICENSE-MIT file in the root directory of this source tree and the Apache
+ * License, Version 2.0 found in the LICENSE-APACHE file in the root directory
+ * of this source tree.
+ */
+
+use futures::{
+    future::FutureExt,
+    stream::Stream,
+    task::{Context, Poll},
+};
+use pin_project::pin_project;
+use std::pin::Pin;
+use std::time::Duration;
+use thiserror::Error;
+use tokio::time::Delay;
+
+/// Error returned when a [StreamWithItemTimeout] doesn't receive an item within its deadline.
+#[derive(Debug, Error)]
+#[error("Stream item timeout with duration {:?} was exceeded", .0)]
+pub struct StreamItemTimeoutError(Duration);
+
+/// A stream that must yield an item within a given duration of the previous one, or it will emit
+/// an error. Unlike [super::StreamWithTimeout], timing out does not end the stream: the inner
+/// stream keeps being polled, so callers can decide whether a timeout is fatal. After a timeout
+/// no further timeout error is emitted until the inner stream yields another item. The clock
+/// starts counting when the stream is created, and is reset every time an item is yielded.
+#[pin_project]
+pub struct StreamWithItemTimeout<S> {
+    #[pin]
+    inner: S,
+    duration: Duration,
+    deadline: Delay,
+    poll_deadline: bool,
+}
+
+impl<S> StreamWithItemTimeout<S> {
+    /// Create a new [StreamWithItemTimeout].
+    pub fn new(inner: S, duration: Duration) -> Self {
+        Self {
+            inner,
+            duration,
+            deadline: tokio::time::delay_for(duration),
+            poll_deadline: true,
+        }
+    }
+}
+
+impl<S: Stream> Stream for StreamWithItemTimeout<S> {
+    type Item = Result<<S as Stream>::Item, StreamItemTimeoutError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        let duration = *this.duration;
+
+        if *this.poll_deadline {
+            match this.deadline.poll_unpin(cx) {
+                Poll::Ready(()) => {
+                    *this.poll_deadline = false;
+                    return Poll::Ready(Some(Err(StreamItemTimeoutError(duration))));
+                }
+                Poll::Pending => {
+                    // Continue
+                }
+            }
+        }
+
+        let res = futures::ready!(this.inner.poll_next(cx));
+
+        if res.is_some() {
+            *this.deadline = tokio::time::delay_for(duration);
+            *this.poll_deadline = true;
+        }
+
+        Poll::Ready(res.map(Ok))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use anyhow::Error;
+    use futures::stream::{StreamExt, TryStreamExt};
+
+    #[tokio::test]
+    async fn test_stream_item_timeout() -> Result<(), Error> {
+        tokio::time::pause();
+
+        let s = async_stream::stream! {
+            yield Result::<(), Error>::Ok(());
+            tokio::time::advance(Duration::from_secs(2)).await;
+            yield Result::<(), Error>::Ok(());
+        };
+
+        let mut s = StreamWithItemTimeout::new(s.boxed(), Duration::from_secs(1));
+
+        assert!(s.try_next().await?.is_some());
+        assert!(s.try_next().await.is_err());
+        assert!(s.try_next().await?.is_some());
+        assert!(s.try_next().await?.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_stream_survives_long_stall_with_single_timeout() -> Result<(), Error> {
+        tokio::time::pause();
+
+        let s = async_stream::stream! {
+            tokio::time::advance(Duration::from_secs(2)).await;
+            tokio::time::advance(Duration::from_secs(2)).await;
+            yield Result::<(), Error>::Ok(());
+        };
+
+        let mut s = StreamWithItemTimeout::new(s.boxed(), Duration::from_secs(1));
+
+        assert!(s.try_next().await.is_err());
+        assert!(s.try_next().await?.is_some());
+        assert!(s.try_next().await?.is_none());
+
+        Ok(())
+    }
+}
@@ -31,6 +31,10 @@ pub struct StreamWithTimeout<S> {
     inner: S,
     duration: Duration,
     done: bool,
+    // Tracks whether `done` was set because the deadline fired, as opposed to the inner stream
+    // having legitimately ended, so `reset_deadline` knows whether it's safe to revive the
+    // stream (polling an already-exhausted inner stream again is against the `Stream` contract).
+    timed_out: bool,
     deadline: Option<Delay>,
 }
 
@@ -41,9 +45,39 @@ impl<S> StreamWithTimeout<S> {
             inner,
             duration,
             done: false,
+            timed_out: false,
             deadline: None,
         }
     }
+
+    /// Reassign the deadline to `duration` from now, discarding whatever time was already
+    /// elapsed. If the stream had previously timed out, this also revives it so it resumes
+    /// polling the inner stream. Has no effect if the inner stream has already legitimately
+    /// ended.
+    pub fn reset_deadline(&mut self, duration: Duration) {
+        self.duration = duration;
+        self.deadline = Some(tokio::time::delay_for(duration));
+        if self.timed_out {
+            self.done = false;
+            self.timed_out = false;
+        }
+    }
+
+    /// Push the current deadline further out by `extra`, without discarding time already
+    /// elapsed. If the deadline hasn't been established yet (the stream hasn't been polled),
+    /// this simply extends the duration that will be used to set it.
+    pub fn extend_deadline(&mut self, extra: Duration) {
+        self.duration += extra;
+        match self.deadline.as_mut() {
+            Some(deadline) => {
+                let new_deadline = deadline.deadline() + extra;
+                deadline.reset(new_deadline);
+            }
+            None => {
+                // Deadline will be established with the extended `duration` on first poll.
+            }
+        }
+    }
 }
 
 impl<S: Stream> Stream for StreamWithTimeout<S> {
@@ -65,6 +99,7 @@ impl<S: Stream> Stream for StreamWithTimeout<S> {
         match deadline.poll_unpin(cx) {
             Poll::Ready(()) => {
                 *this.done = true;
+                *this.timed_out = true;
                 return Poll::Ready(Some(Err(StreamTimeoutError(duration))));
             }
             Poll::Pending => {
@@ -149,4 +184,69 @@ mod test {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_reset_deadline_revives_stream() -> Result<(), Error> {
+        tokio::time::pause();
+
+        let s = async_stream::stream! {
+            yield Result::<(), Error>::Ok(());
+            tokio::time::advance(Duration::from_secs(2)).await;
+            yield Result::<(), Error>::Ok(());
+        };
+
+        let mut s = StreamWithTimeout::new(s.boxed(), Duration::from_secs(1));
+
+        assert!(s.try_next().await?.is_some());
+        assert!(s.try_next().await.is_err());
+
+        s.reset_deadline(Duration::from_secs(1));
+
+        assert!(s.try_next().await?.is_some());
+        assert!(s.try_next().await?.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_reset_deadline_does_not_revive_finished_stream() -> Result<(), Error> {
+        tokio::time::pause();
+
+        let s = async_stream::stream! {
+            yield Result::<(), Error>::Ok(());
+        };
+
+        let mut s = StreamWithTimeout::new(s.boxed(), Duration::from_secs(1));
+
+        assert!(s.try_next().await?.is_some());
+        assert!(s.try_next().await?.is_none());
+
+        s.reset_deadline(Duration::from_secs(1));
+
+        assert!(s.try_next().await?.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_extend_deadline_avoids_timeout() -> Result<(), Error> {
+        tokio::time::pause();
+
+        let s = async_stream::stream! {
+            tokio::time::advance(Duration::from_secs(1)).await;
+            yield Result::<(), Error>::Ok(());
+        };
+
+        let mut s = StreamWithTimeout::new(s.boxed(), Duration::from_secs(1));
+
+        let mut fut = s.try_next();
+        assert!(futures::poll!(&mut fut).is_pending());
+        drop(fut);
+
+        s.extend_deadline(Duration::from_secs(2));
+
+        assert!(s.try_next().await?.is_some());
+
+        Ok(())
+    }
 }
\ No newline at end of file
@@ -0,0 +1,89 @@
+/*
+ * Copyright (c) Facebook, Inc. and its affiliates.
+ *
+ * This source code is licensed under both the MIT license found in the
+ * LICENSE-MIT file in the root directory of this source tree and the Apache
+ * License, Version 2.0 found in the LICENSE-APACHE file in the root directory
+ * of this source tree.
+ */
+
+use futures::{
+    future::FutureExt,
+    stream::Stream,
+    task::{Context, Poll},
+};
+use pin_project::pin_project;
+use std::pin::Pin;
+use std::time::Duration;
+use tokio::time::Delay;
+
+/// A stream that enforces a minimum delay of `duration` between yielded items, slowing a fast
+/// producer down to at most one item per `duration`. The first item is let through immediately.
+#[pin_project]
+pub struct Throttle<S> {
+    #[pin]
+    inner: S,
+    duration: Duration,
+    deadline: Delay,
+}
+
+impl<S> Throttle<S> {
+    /// Create a new [Throttle].
+    pub fn new(inner: S, duration: Duration) -> Self {
+        Self {
+            inner,
+            duration,
+            // Start off already elapsed so the first item is not delayed.
+            deadline: tokio::time::delay_for(Duration::from_secs(0)),
+        }
+    }
+}
+
+impl<S: Stream> Stream for Throttle<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        futures::ready!(this.deadline.poll_unpin(cx));
+
+        let res = futures::ready!(this.inner.poll_next(cx));
+
+        if res.is_some() {
+            *this.deadline = tokio::time::delay_for(*this.duration);
+        }
+
+        Poll::Ready(res)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use anyhow::Error;
+    use futures::stream::StreamExt;
+
+    #[tokio::test]
+    async fn test_throttle_paces_items() -> Result<(), Error> {
+        tokio::time::pause();
+
+        let s = async_stream::stream! {
+            yield 1;
+            yield 2;
+            yield 3;
+        };
+
+        let mut s = Throttle::new(s.boxed(), Duration::from_secs(1));
+
+        assert_eq!(s.next().await, Some(1));
+
+        let next = tokio::time::timeout(Duration::from_millis(1), s.next());
+        assert!(next.await.is_err());
+
+        tokio::time::advance(Duration::from_secs(1)).await;
+        assert_eq!(s.next().await, Some(2));
+
+        Ok(())
+    }
+}